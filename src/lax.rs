@@ -0,0 +1,72 @@
+//! A lenient deserializer: instead of aborting on a `FromStr` failure like the
+//! crate root and [`opt`](super::opt) do, a parse error falls back to a default
+//! value, so a config with an occasionally-malformed field still loads.
+//!
+//! [`deserialize`] requires `T: Default`, which rules out types like `IpAddr` or
+//! `Url` that have no sensible default — for those, use [`opt_deserialize`]
+//! instead, which only requires `FromStr` and turns a bad parse into `None`.
+//!
+//! # Example
+//!
+//! ```rust
+//! # #[macro_use] extern crate serde_derive;
+//! /// A structure that falls back to port 0 on bad input.
+//! #[derive(Deserialize)]
+//! # #[derive(PartialEq, Debug)]
+//! struct WithPort {
+//!     #[serde(deserialize_with = "serde_str::lax::deserialize")]
+//!     port: u16,
+//! }
+//!
+//! use serde_json::from_str;
+//! # fn main() -> serde_json::Result<()> {
+//! let with_port: WithPort = from_str(r#"{"port": "8080"}"#)?;
+//! assert_eq!(with_port, WithPort { port: 8080 });
+//!
+//! let with_port: WithPort = from_str(r#"{"port": "not-a-port"}"#)?;
+//! assert_eq!(with_port, WithPort { port: 0 });
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`opt_deserialize`] does the same for a stray IP or URL, turning an
+//! unparseable string into `None` rather than requiring `T::default()`.
+//!
+//! ```rust
+//! use std::net::IpAddr;
+//!
+//! # fn main() -> serde_json::Result<()> {
+//! let ip: Option<IpAddr> = serde_str::lax::opt_deserialize(
+//!     &mut serde_json::Deserializer::from_str(r#""not-an-ip""#),
+//! )?;
+//! assert_eq!(ip, None);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer};
+
+/// Deserialize function, see [mod docs examples](https://docs.rs/serde_str/*/serde_str/lax/index.html) to see how to use it
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: FromStr + Default,
+    T::Err: Display,
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    Ok(T::from_str(&s).unwrap_or_default())
+}
+
+/// Deserializes into `Some(T)`, or `None` if the string fails to parse.
+pub fn opt_deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    T: FromStr,
+    T::Err: Display,
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    Ok(T::from_str(&s).ok())
+}
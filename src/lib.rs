@@ -43,23 +43,60 @@ use serde::{
 	de::{
 		Deserialize,
 		Error as DeserializeError,
+		Visitor,
 	},
 	Deserializer,
+	Serialize,
 	Serializer,
 };
 use std::{
 	fmt,
+	marker::PhantomData,
+	ops::{Deref, DerefMut},
 	str::FromStr,
 };
 
+struct StrVisitor<T>(PhantomData<T>);
+
+impl<'de, T: FromStr> Visitor<'de> for StrVisitor<T>
+where
+	T::Err: fmt::Display,
+{
+	type Value = T;
+
+	fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+		formatter.write_str("string")
+	}
+
+	fn visit_str<E>(self, v: &str) -> Result<T, E>
+	where
+		E: DeserializeError,
+	{
+		T::from_str(v).map_err(DeserializeError::custom)
+	}
+
+	fn visit_borrowed_str<E>(self, v: &str) -> Result<T, E>
+	where
+		E: DeserializeError,
+	{
+		T::from_str(v).map_err(DeserializeError::custom)
+	}
+
+	fn visit_string<E>(self, v: String) -> Result<T, E>
+	where
+		E: DeserializeError,
+	{
+		T::from_str(&v).map_err(DeserializeError::custom)
+	}
+}
+
 /// Deserialize function, see [crate docs examples](https://docs.rs/serde_strz) to see how to use it
 pub fn deserialize<'de, D, T: FromStr>(deserializer: D) -> Result<T, D::Error>
 where
 	D: Deserializer<'de>,
 	<T as FromStr>::Err: fmt::Display,
 {
-	let s = String::deserialize(deserializer)?;
-	T::from_str(&s).map_err(DeserializeError::custom)
+	deserializer.deserialize_str(StrVisitor(PhantomData))
 }
 
 /// Serialize function, see [crate docs examples](https://docs.rs/serde_strz) to see how to use it
@@ -74,5 +111,139 @@ where
 	serializer.collect_str(value)
 }
 
+/// A newtype that bridges `T: FromStr + Display` into full `Serialize`/`Deserialize`
+/// impls, for use where `#[serde(with = ...)]` doesn't apply: collection elements,
+/// map values, or a bare value sent through a channel.
+///
+/// ```rust
+/// use std::net::IpAddr;
+/// use serde_str::Serde;
+///
+/// # fn main() -> serde_json::Result<()> {
+/// let ips: Vec<Serde<IpAddr>> = serde_json::from_str(r#"["127.0.0.1", "::1"]"#)?;
+/// assert_eq!(*ips[0], "127.0.0.1".parse::<IpAddr>().unwrap());
+/// assert_eq!(serde_json::to_string(&ips)?, r#"["127.0.0.1","::1"]"#);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Serde<T>(pub T);
+
+impl<T> Serde<T> {
+	/// Unwraps the value, discarding the wrapper.
+	pub fn into_inner(self) -> T {
+		self.0
+	}
+}
+
+impl<T> Deref for Serde<T> {
+	type Target = T;
+	fn deref(&self) -> &T {
+		&self.0
+	}
+}
+
+impl<T> DerefMut for Serde<T> {
+	fn deref_mut(&mut self) -> &mut T {
+		&mut self.0
+	}
+}
+
+impl<T: fmt::Display> Serialize for Serde<T> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		serialize(&self.0, serializer)
+	}
+}
+
+impl<'de, T: FromStr> Deserialize<'de> for Serde<T>
+where
+	<T as FromStr>::Err: fmt::Display,
+{
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		deserialize(deserializer).map(Serde)
+	}
+}
+
+/// A thin borrow of `T` for serializing without cloning into a [`Serde`] first.
+///
+/// ```rust
+/// use std::net::IpAddr;
+/// use serde_str::Ser;
+///
+/// # fn main() -> serde_json::Result<()> {
+/// let ip: IpAddr = "127.0.0.1".parse().unwrap();
+/// assert_eq!(serde_json::to_string(&Ser(&ip))?, r#""127.0.0.1""#);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Ser<'a, T: 'a>(pub &'a T);
+
+impl<'a, T: fmt::Display> Serialize for Ser<'a, T> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		serialize(self.0, serializer)
+	}
+}
+
+/// The deserializing half of [`Serde`], for call sites that only ever read a value
+/// (e.g. the receiving end of an IPC channel) and have no use for `Serialize`.
+///
+/// ```rust
+/// use std::net::IpAddr;
+/// use serde_str::De;
+///
+/// # fn main() -> serde_json::Result<()> {
+/// let ip: De<IpAddr> = serde_json::from_str(r#""127.0.0.1""#)?;
+/// assert_eq!(ip.into_inner(), "127.0.0.1".parse::<IpAddr>().unwrap());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct De<T>(pub T);
+
+impl<T> De<T> {
+	/// Unwraps the value, discarding the wrapper.
+	pub fn into_inner(self) -> T {
+		self.0
+	}
+}
+
+impl<T> Deref for De<T> {
+	type Target = T;
+	fn deref(&self) -> &T {
+		&self.0
+	}
+}
+
+impl<T> DerefMut for De<T> {
+	fn deref_mut(&mut self) -> &mut T {
+		&mut self.0
+	}
+}
+
+impl<'de, T: FromStr> Deserialize<'de> for De<T>
+where
+	<T as FromStr>::Err: fmt::Display,
+{
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		deserialize(deserializer).map(De)
+	}
+}
+
 pub mod emp;
+pub mod lax;
+pub mod map;
 pub mod opt;
+pub mod sep;
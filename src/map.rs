@@ -0,0 +1,93 @@
+//! (De)serializes a map whose keys implement `FromStr`/`Display` rather than
+//! `Serialize`/`Deserialize`, by rendering each key through `Display` on the way
+//! out and parsing it back with `FromStr` on the way in — so `BTreeMap<IpAddr, V>`
+//! or `HashMap<SocketAddr, V>` can serialize to formats like JSON that require
+//! string object keys.
+//!
+//! # Example
+//!
+//! ```rust
+//! # #[macro_use] extern crate serde_derive;
+//! use std::collections::BTreeMap;
+//! use std::net::IpAddr;
+//!
+//! /// A structure mapping IP addresses to a weight.
+//! #[derive(Serialize, Deserialize)]
+//! # #[derive(PartialEq, Debug)]
+//! struct Weights {
+//!     #[serde(with = "serde_str::map")]
+//!     weight: BTreeMap<IpAddr, u32>,
+//! }
+//!
+//! use serde_json::{from_str, to_string};
+//! # fn main() -> serde_json::Result<()> {
+//! let weights: Weights = from_str(r#"{"weight": {"127.0.0.1": 1}}"#)?;
+//! assert_eq!(weights.weight[&"127.0.0.1".parse::<IpAddr>().unwrap()], 1);
+//! assert_eq!(to_string(&weights)?, r#"{"weight":{"127.0.0.1":1}}"#);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::{self, Display};
+use std::iter::FromIterator;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use serde::de::{self, MapAccess, Visitor};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+struct MapVisitor<M, K, V>(PhantomData<(M, K, V)>);
+
+impl<'de, M, K, V> Visitor<'de> for MapVisitor<M, K, V>
+where
+    M: FromIterator<(K, V)>,
+    K: FromStr,
+    K::Err: Display,
+    V: Deserialize<'de>,
+{
+    type Value = M;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a map")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<M, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut items = Vec::with_capacity(map.size_hint().unwrap_or(0));
+        while let Some((k, v)) = map.next_entry::<String, V>()? {
+            let key = K::from_str(&k).map_err(de::Error::custom)?;
+            items.push((key, v));
+        }
+        Ok(M::from_iter(items))
+    }
+}
+
+/// Serialize function, see [mod docs examples](https://docs.rs/serde_str/*/serde_str/map/index.html) to see how to use it
+pub fn serialize<'a, M, K, V, S>(value: &'a M, serializer: S) -> Result<S::Ok, S::Error>
+where
+    &'a M: IntoIterator<Item = (&'a K, &'a V)>,
+    K: Display + 'a,
+    V: Serialize + 'a,
+    S: Serializer,
+{
+    let mut map = serializer.serialize_map(None)?;
+    for (k, v) in value {
+        map.serialize_entry(&k.to_string(), v)?;
+    }
+    map.end()
+}
+
+/// Deserialize function, see [mod docs examples](https://docs.rs/serde_str/*/serde_str/map/index.html) to see how to use it
+pub fn deserialize<'de, M, K, V, D>(deserializer: D) -> Result<M, D::Error>
+where
+    M: FromIterator<(K, V)>,
+    K: FromStr,
+    K::Err: Display,
+    V: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_map(MapVisitor(PhantomData))
+}
@@ -0,0 +1,158 @@
+//! (De)serializes a whole collection as a single separator-delimited string, so a
+//! `Vec<IpAddr>` or `HashSet<u16>` round-trips through JSON as `"10.0.0.1,10.0.0.2"`
+//! instead of an array.
+//!
+//! The separator is chosen by a marker type implementing [`Separator`]. Since
+//! `#[serde(with = ...)]` can't pass that marker as a turbofish, fix it once in a
+//! thin local module that forwards to [`serialize`]/[`deserialize`].
+//!
+//! # Example
+//!
+//! ```rust
+//! # #[macro_use] extern crate serde_derive;
+//! use serde_str::sep::Separator;
+//!
+//! /// Joins/splits on a comma.
+//! struct Comma;
+//! impl Separator for Comma {
+//!     fn separator() -> &'static str { "," }
+//! }
+//!
+//! mod comma_ports {
+//!     use super::Comma;
+//!     use serde::{Deserializer, Serializer};
+//!     use serde_str::sep;
+//!
+//!     pub fn serialize<S: Serializer>(value: &Vec<u16>, serializer: S) -> Result<S::Ok, S::Error> {
+//!         sep::serialize::<_, _, Comma, _>(value, serializer)
+//!     }
+//!
+//!     pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u16>, D::Error> {
+//!         sep::deserialize::<_, _, Comma, _>(deserializer)
+//!     }
+//! }
+//!
+//! /// A structure holding a comma-separated list of ports.
+//! #[derive(Serialize, Deserialize)]
+//! # #[derive(PartialEq, Debug)]
+//! struct WithPorts {
+//!     #[serde(with = "comma_ports")]
+//!     ports: Vec<u16>,
+//! }
+//!
+//! use serde_json::{from_str, to_string};
+//! # fn main() -> serde_json::Result<()> {
+//! let with_ports: WithPorts = from_str(r#"{"ports": "80,443,8080"}"#)?;
+//! assert_eq!(with_ports, WithPorts { ports: vec![80, 443, 8080] });
+//! assert_eq!(to_string(&with_ports)?, r#"{"ports":"80,443,8080"}"#);
+//!
+//! let empty: WithPorts = from_str(r#"{"ports": ""}"#)?;
+//! assert_eq!(empty, WithPorts { ports: vec![] });
+//! assert_eq!(to_string(&empty)?, r#"{"ports":""}"#);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::{self, Display};
+use std::iter::FromIterator;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use serde::de::{self, Visitor};
+use serde::{Deserializer, Serializer};
+
+/// A marker type that names the separator used to join and split a collection.
+pub trait Separator {
+    /// The separator string.
+    fn separator() -> &'static str;
+}
+
+struct Joined<'a, C, Sep> {
+    value: &'a C,
+    _sep: PhantomData<Sep>,
+}
+
+impl<'a, C, T, Sep> Display for Joined<'a, C, Sep>
+where
+    &'a C: IntoIterator<Item = &'a T>,
+    T: Display + 'a,
+    Sep: Separator,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, item) in self.value.into_iter().enumerate() {
+            if i > 0 {
+                f.write_str(Sep::separator())?;
+            }
+            Display::fmt(item, f)?;
+        }
+        Ok(())
+    }
+}
+
+struct SepVisitor<C, T, Sep>(PhantomData<(C, T, Sep)>);
+
+impl<'de, C, T, Sep> Visitor<'de> for SepVisitor<C, T, Sep>
+where
+    C: FromIterator<T>,
+    T: FromStr,
+    T::Err: Display,
+    Sep: Separator,
+{
+    type Value = C;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a delimited string")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if v.is_empty() {
+            return Ok(C::from_iter(None));
+        }
+        v.split(Sep::separator())
+            .map(|piece| T::from_str(piece).map_err(de::Error::custom))
+            .collect()
+    }
+
+    fn visit_borrowed_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_str(v)
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_str(&v)
+    }
+}
+
+/// Serialize function, see [mod docs examples](https://docs.rs/serde_str/*/serde_str/sep/index.html) to see how to use it
+pub fn serialize<'a, C, T, Sep, S>(value: &'a C, serializer: S) -> Result<S::Ok, S::Error>
+where
+    &'a C: IntoIterator<Item = &'a T>,
+    T: Display + 'a,
+    Sep: Separator,
+    S: Serializer,
+{
+    serializer.collect_str(&Joined::<C, Sep> {
+        value,
+        _sep: PhantomData,
+    })
+}
+
+/// Deserialize function, see [mod docs examples](https://docs.rs/serde_str/*/serde_str/sep/index.html) to see how to use it
+pub fn deserialize<'de, C, T, Sep, D>(deserializer: D) -> Result<C, D::Error>
+where
+    C: FromIterator<T>,
+    T: FromStr,
+    T::Err: Display,
+    Sep: Separator,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_str(SepVisitor::<C, T, Sep>(PhantomData))
+}